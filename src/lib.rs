@@ -35,8 +35,8 @@
 
 #[cfg(not(test))]
 use std::io::BufRead;
-use std::{borrow::Cow, env::var};
-use testcontainers_modules::testcontainers::Container;
+use std::{borrow::Cow, env::var, time::Duration};
+use testcontainers_modules::testcontainers::{runners::AsyncRunner, Container};
 pub use testcontainers_modules::{
     neo4j::{Neo4j, Neo4jImage},
     testcontainers::clients,
@@ -135,8 +135,44 @@ pub trait Neo4jImageExt {
 
     /// Return the connection URI to connect to the Neo4j server via HTTP over IPv6.
     fn http_uri_ipv6(&self) -> String;
+
+    /// Return the connection URI another container on the same Docker network can use to reach
+    /// this container's Bolt port via its [`Neo4jRunnableImageExt::with_network_alias`], using the
+    /// container-internal port rather than the host-mapped one.
+    fn bolt_uri_in_network(&self, alias: &str) -> String;
+
+    /// Same as [`Self::bolt_uri_in_network`], but for the HTTP port.
+    fn http_uri_in_network(&self, alias: &str) -> String;
+
+    /// Query the server's HTTP discovery endpoint for its `neo4j_version` and `neo4j_edition`,
+    /// instead of issuing `CALL dbms.components()` over Bolt.
+    ///
+    /// # Errors
+    /// Returns an error if the endpoint cannot be reached, or if the response is missing either
+    /// field.
+    fn discover_version(
+        &self,
+    ) -> impl std::future::Future<
+        Output = Result<Neo4jVersion, Box<dyn std::error::Error + Sync + Send + 'static>>,
+    > + Send;
 }
 
+/// The server version and edition, as reported by the HTTP discovery endpoint. Returned by
+/// [`Neo4jImageExt::discover_version`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neo4jVersion {
+    /// The `neo4j_version` field, e.g. `"5.20.0"`.
+    pub version: String,
+    /// The `neo4j_edition` field, either `"community"` or `"enterprise"`.
+    pub edition: String,
+}
+
+/// The container-internal (unmapped) Bolt port, as exposed by the official Neo4j image.
+const BOLT_PORT: u16 = 7687;
+
+/// The container-internal (unmapped) HTTP port, as exposed by the official Neo4j image.
+const HTTP_PORT: u16 = 7474;
+
 impl Neo4jImageExt for Neo4jImage {
     fn bolt_uri_ipv4(&self) -> String {
         format!("bolt://127.0.0.1:{}", self.bolt_port_ipv4())
@@ -153,6 +189,74 @@ impl Neo4jImageExt for Neo4jImage {
     fn http_uri_ipv6(&self) -> String {
         format!("http://[::1]:{}", self.http_port_ipv6())
     }
+
+    fn bolt_uri_in_network(&self, alias: &str) -> String {
+        format!("bolt://{alias}:{BOLT_PORT}")
+    }
+
+    fn http_uri_in_network(&self, alias: &str) -> String {
+        format!("http://{alias}:{HTTP_PORT}")
+    }
+
+    async fn discover_version(
+        &self,
+    ) -> Result<Neo4jVersion, Box<dyn std::error::Error + Sync + Send + 'static>> {
+        let body: serde_json::Value = reqwest::get(self.http_uri_ipv4()).await?.json().await?;
+
+        let version = body
+            .get("neo4j_version")
+            .and_then(serde_json::Value::as_str)
+            .ok_or("discovery response is missing `neo4j_version`")?
+            .to_owned();
+        let edition = body
+            .get("neo4j_edition")
+            .and_then(serde_json::Value::as_str)
+            .ok_or("discovery response is missing `neo4j_edition`")?
+            .to_owned();
+
+        Ok(Neo4jVersion { version, edition })
+    }
+}
+
+/// A Neo4j plugin that can be provisioned via [`Neo4jRunnableImageExt::with_plugins`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Plugin {
+    /// [APOC](https://neo4j.com/labs/apoc/), the most commonly used procedure library.
+    Apoc,
+    /// The APOC Extended library, which ships separately from core APOC since Neo4j 5.
+    ApocExtended,
+    /// [Graph Data Science](https://neo4j.com/product/graph-data-science/), exposing `gds.*`.
+    GraphDataScience,
+    /// [Bloom](https://neo4j.com/product/bloom/), Neo4j's graph visualization tool.
+    ///
+    /// Bloom is enterprise-only, so requesting it also requires accepting the Neo4j Enterprise
+    /// Edition license, see [`Neo4jRunnableImageExt::with_enterprise_edition`].
+    Bloom,
+}
+
+impl Plugin {
+    fn plugin_name(self) -> &'static str {
+        match self {
+            Self::Apoc => "apoc",
+            Self::ApocExtended => "apoc-extended",
+            Self::GraphDataScience => "graph-data-science",
+            Self::Bloom => "bloom",
+        }
+    }
+
+    fn is_enterprise_only(self) -> bool {
+        matches!(self, Self::Bloom)
+    }
+
+    /// The `dbms.security.procedures.*` allowlist entry this plugin's procedures need to be
+    /// callable, if any.
+    fn procedure_allowlist(self) -> Option<&'static str> {
+        match self {
+            Self::Apoc | Self::ApocExtended => Some("apoc.*"),
+            Self::GraphDataScience => Some("gds.*"),
+            Self::Bloom => None,
+        }
+    }
 }
 
 /// Extension trait for [`RunnableImage<Neo4jImage>`] to allow the usage of Neo4j Enterprise
@@ -167,6 +271,82 @@ pub trait Neo4jRunnableImageExt: Sized {
     fn with_enterprise_edition(
         self,
     ) -> Result<Self, Box<dyn std::error::Error + Sync + Send + 'static>>;
+
+    /// Install the given plugins (APOC, Graph Data Science, Bloom, ...) and allowlist the
+    /// procedures they add, so they can actually be called from Cypher.
+    ///
+    /// Plugins that are enterprise-only are gated behind the same license acceptance check as
+    /// [`Self::with_enterprise_edition`], and switch to the enterprise edition automatically.
+    ///
+    /// # Errors
+    /// Returns an error under the same conditions as [`Self::with_enterprise_edition`], if an
+    /// enterprise-only plugin is requested without having accepted the license.
+    fn with_plugins(
+        self,
+        plugins: &[Plugin],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send + 'static>>;
+
+    /// Attach the container to a user-defined Docker network, so it can be reached by other
+    /// containers on that network, e.g. the system under test.
+    ///
+    /// Use together with [`Self::with_network_alias`] and [`Neo4jImageExt::bolt_uri_in_network`] /
+    /// [`Neo4jImageExt::http_uri_in_network`] to build the URI other containers should connect to.
+    #[must_use]
+    fn with_network(self, network: impl Into<String>) -> Self;
+
+    /// Give the container a hostname alias on its Docker network, so other containers can resolve
+    /// it by name instead of by IP.
+    #[must_use]
+    fn with_network_alias(self, alias: impl Into<String>) -> Self;
+
+    /// Configure the JVM heap and page cache sizes, e.g. `"512m"`/`"1G"`.
+    ///
+    /// CI runners frequently OOM the default JVM settings under parallel test loads, so it's
+    /// often worth trimming these down explicitly.
+    #[must_use]
+    fn with_memory_config(
+        self,
+        heap_initial: impl Into<String>,
+        heap_max: impl Into<String>,
+        pagecache: impl Into<String>,
+    ) -> Self;
+
+    /// Wait for the Bolt protocol to actually accept connections, not just for the mapped port to
+    /// be open. Uses [`DEFAULT_BOLT_READINESS_TIMEOUT`] and [`DEFAULT_BOLT_READINESS_INTERVAL`].
+    ///
+    /// This is useful because Neo4j reports its port as open before it is ready to serve Bolt
+    /// auth, so the first query issued right after `start()` can otherwise race the server.
+    #[must_use]
+    fn with_bolt_readiness(self) -> Neo4jStartupBuilder {
+        self.with_bolt_readiness_timeout(
+            DEFAULT_BOLT_READINESS_TIMEOUT,
+            DEFAULT_BOLT_READINESS_INTERVAL,
+        )
+    }
+
+    /// Same as [`Self::with_bolt_readiness`], but with an explicit timeout and initial polling
+    /// interval.
+    #[must_use]
+    fn with_bolt_readiness_timeout(
+        self,
+        timeout: Duration,
+        interval: Duration,
+    ) -> Neo4jStartupBuilder;
+
+    /// Run the given Cypher scripts against the `neo4j` database, in order, once the container
+    /// has started. Each script is executed through the HTTP transactional Cypher endpoint, so
+    /// this does not pull a Bolt client into the crate.
+    ///
+    /// This always waits for the HTTP endpoint to start accepting requests before running the
+    /// first script, independent of [`Self::with_bolt_readiness`], since Neo4j reports its ports
+    /// open before either protocol is actually ready to serve.
+    ///
+    /// Use [`Neo4jStartupBuilder::with_init_cypher_database`] to target a different database.
+    #[must_use]
+    fn with_init_cypher(
+        self,
+        scripts: impl IntoIterator<Item = CypherScript>,
+    ) -> Neo4jStartupBuilder;
 }
 
 impl Neo4jRunnableImageExt for RunnableImage<Neo4jImage> {
@@ -225,6 +405,388 @@ impl Neo4jRunnableImageExt for RunnableImage<Neo4jImage> {
             .with_tag(version);
         Ok(this)
     }
+
+    fn with_plugins(
+        self,
+        plugins: &[Plugin],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send + 'static>> {
+        if plugins.is_empty() {
+            return Ok(self);
+        }
+
+        let mut this = self;
+        if plugins.iter().any(Plugin::is_enterprise_only) {
+            this = this.with_enterprise_edition()?;
+        }
+
+        // Neo4j 4 uses the `neo4j-labs-plugins` init script and `NEO4JLABS_PLUGINS`, Neo4j 5
+        // renamed it to `NEO4J_PLUGINS`.
+        let plugins_env_var = if this.descriptor().contains(":4") {
+            "NEO4JLABS_PLUGINS"
+        } else {
+            "NEO4J_PLUGINS"
+        };
+        let plugins_json = format!(
+            "[{}]",
+            plugins
+                .iter()
+                .map(|plugin| format!("{:?}", plugin.plugin_name()))
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        this = this.with_env_var((plugins_env_var, plugins_json));
+
+        let allowlist = plugins
+            .iter()
+            .filter_map(Plugin::procedure_allowlist)
+            .collect::<Vec<_>>()
+            .join(",");
+        if !allowlist.is_empty() {
+            this = this
+                .with_env_var((
+                    "NEO4J_dbms_security_procedures_unrestricted",
+                    allowlist.clone(),
+                ))
+                .with_env_var(("NEO4J_dbms_security_procedures_allowlist", allowlist));
+        }
+
+        Ok(this)
+    }
+
+    fn with_network(self, network: impl Into<String>) -> Self {
+        RunnableImage::with_network(self, network.into())
+    }
+
+    fn with_network_alias(self, alias: impl Into<String>) -> Self {
+        RunnableImage::with_network_aliases(self, [alias.into()])
+    }
+
+    fn with_memory_config(
+        self,
+        heap_initial: impl Into<String>,
+        heap_max: impl Into<String>,
+        pagecache: impl Into<String>,
+    ) -> Self {
+        self.with_env_var((
+            "NEO4J_server_memory_heap_initial__size",
+            heap_initial.into(),
+        ))
+        .with_env_var(("NEO4J_server_memory_heap_max__size", heap_max.into()))
+        .with_env_var(("NEO4J_server_memory_pagecache_size", pagecache.into()))
+    }
+
+    fn with_bolt_readiness_timeout(
+        self,
+        timeout: Duration,
+        interval: Duration,
+    ) -> Neo4jStartupBuilder {
+        Neo4jStartupBuilder {
+            image: self,
+            bolt_readiness: Some(BoltReadiness { timeout, interval }),
+            init_cypher: Vec::new(),
+            init_cypher_database: DEFAULT_INIT_CYPHER_DATABASE.to_owned(),
+        }
+    }
+
+    fn with_init_cypher(
+        self,
+        scripts: impl IntoIterator<Item = CypherScript>,
+    ) -> Neo4jStartupBuilder {
+        Neo4jStartupBuilder {
+            image: self,
+            bolt_readiness: None,
+            init_cypher: Vec::new(),
+            init_cypher_database: DEFAULT_INIT_CYPHER_DATABASE.to_owned(),
+        }
+        .with_init_cypher(scripts)
+    }
+}
+
+/// Default amount of time [`Neo4jRunnableImageExt::with_bolt_readiness`] waits for the Bolt
+/// protocol to become ready before giving up.
+pub const DEFAULT_BOLT_READINESS_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Initial backoff interval used by [`Neo4jRunnableImageExt::with_bolt_readiness`] between two
+/// Bolt handshake attempts. The interval doubles after every failed attempt, up to 5 seconds.
+pub const DEFAULT_BOLT_READINESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The database [`Neo4jStartupBuilder::with_init_cypher_database`] targets unless overridden.
+pub const DEFAULT_INIT_CYPHER_DATABASE: &str = "neo4j";
+
+#[derive(Debug, Clone, Copy)]
+struct BoltReadiness {
+    timeout: Duration,
+    interval: Duration,
+}
+
+/// A single Cypher statement to run against the database once the container has started, either
+/// inline or read from a file. Used with [`Neo4jRunnableImageExt::with_init_cypher`].
+#[derive(Debug, Clone)]
+pub enum CypherScript {
+    /// An inline Cypher statement.
+    Statement(String),
+    /// A path to a file containing a single Cypher statement, read when the container starts.
+    File(std::path::PathBuf),
+}
+
+impl CypherScript {
+    /// Create an inline Cypher statement.
+    #[must_use]
+    pub fn statement(statement: impl Into<String>) -> Self {
+        Self::Statement(statement.into())
+    }
+
+    /// Create a script backed by a file path.
+    #[must_use]
+    pub fn file(path: impl Into<std::path::PathBuf>) -> Self {
+        Self::File(path.into())
+    }
+}
+
+/// A [`RunnableImage<Neo4jImage>`] with additional startup steps — Bolt readiness waiting and/or
+/// Cypher initialization scripts — that run once the container has started, before
+/// [`Self::start`] returns. Returned by [`Neo4jRunnableImageExt::with_bolt_readiness`] and
+/// [`Neo4jRunnableImageExt::with_init_cypher`].
+#[derive(Debug)]
+pub struct Neo4jStartupBuilder {
+    image: RunnableImage<Neo4jImage>,
+    bolt_readiness: Option<BoltReadiness>,
+    init_cypher: Vec<CypherScript>,
+    init_cypher_database: String,
+}
+
+impl Neo4jStartupBuilder {
+    /// Also wait for the Bolt protocol to actually accept connections, not just for the mapped
+    /// port to be open. Uses [`DEFAULT_BOLT_READINESS_TIMEOUT`] and
+    /// [`DEFAULT_BOLT_READINESS_INTERVAL`].
+    #[must_use]
+    pub fn with_bolt_readiness(self) -> Self {
+        self.with_bolt_readiness_timeout(
+            DEFAULT_BOLT_READINESS_TIMEOUT,
+            DEFAULT_BOLT_READINESS_INTERVAL,
+        )
+    }
+
+    /// Same as [`Self::with_bolt_readiness`], but with an explicit timeout and initial polling
+    /// interval.
+    #[must_use]
+    pub fn with_bolt_readiness_timeout(mut self, timeout: Duration, interval: Duration) -> Self {
+        self.bolt_readiness = Some(BoltReadiness { timeout, interval });
+        self
+    }
+
+    /// Also run the given Cypher scripts against [`Self::with_init_cypher_database`]'s database,
+    /// in order, once the container has started.
+    #[must_use]
+    pub fn with_init_cypher(mut self, scripts: impl IntoIterator<Item = CypherScript>) -> Self {
+        self.init_cypher.extend(scripts);
+        self
+    }
+
+    /// Run the init Cypher scripts against `database` instead of [`DEFAULT_INIT_CYPHER_DATABASE`].
+    /// Only relevant for Neo4j 5's multi-database support.
+    #[must_use]
+    pub fn with_init_cypher_database(mut self, database: impl Into<String>) -> Self {
+        self.init_cypher_database = database.into();
+        self
+    }
+
+    /// Start the container, then run the configured startup steps (Bolt readiness wait, Cypher
+    /// initialization) before returning it.
+    ///
+    /// # Errors
+    /// Returns an error if the Bolt handshake does not succeed within its timeout, if a script
+    /// file cannot be read, or if Neo4j reports an error while running a script.
+    pub async fn start(
+        self,
+    ) -> Result<Container<'static, Neo4jImage>, Box<dyn std::error::Error + Sync + Send + 'static>>
+    {
+        let container = self.image.start().await;
+
+        if let Some(bolt_readiness) = self.bolt_readiness {
+            wait_for_bolt_handshake(
+                container.image(),
+                bolt_readiness.timeout,
+                bolt_readiness.interval,
+            )
+            .await?;
+        }
+
+        run_init_cypher(
+            container.image(),
+            &self.init_cypher,
+            &self.init_cypher_database,
+        )
+        .await?;
+
+        Ok(container)
+    }
+}
+
+/// Default amount of time [`run_init_cypher`] waits for the HTTP transactional Cypher endpoint to
+/// start accepting requests before giving up.
+const DEFAULT_HTTP_READINESS_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Initial backoff interval [`run_init_cypher`] waits between two attempts at reaching the HTTP
+/// endpoint. The interval doubles after every failed attempt, up to 5 seconds.
+const DEFAULT_HTTP_READINESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Poll the HTTP discovery root until it answers, so [`run_init_cypher`] doesn't race the same
+/// "port open but not yet serving" startup window that [`wait_for_bolt_handshake`] closes for
+/// Bolt. Unlike the Bolt readiness wait, this always runs, since executing a script against an
+/// endpoint that isn't up yet is a hard, non-retried failure otherwise.
+async fn wait_for_http_ready(
+    image: &Neo4jImage,
+) -> Result<(), Box<dyn std::error::Error + Sync + Send + 'static>> {
+    let http_uri = image.http_uri_ipv4();
+    let deadline = tokio::time::Instant::now() + DEFAULT_HTTP_READINESS_TIMEOUT;
+    let mut backoff = DEFAULT_HTTP_READINESS_INTERVAL;
+
+    loop {
+        match reqwest::get(&http_uri).await {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(_) | Err(_) => {
+                let now = tokio::time::Instant::now();
+                if now >= deadline {
+                    return Err(format!(
+                        "Neo4j's HTTP endpoint at {http_uri} did not become ready within {:?}",
+                        DEFAULT_HTTP_READINESS_TIMEOUT
+                    )
+                    .into());
+                }
+                tokio::time::sleep(backoff.min(deadline - now)).await;
+                backoff = (backoff * 2).min(Duration::from_secs(5));
+            }
+        }
+    }
+}
+
+async fn run_init_cypher(
+    image: &Neo4jImage,
+    scripts: &[CypherScript],
+    database: &str,
+) -> Result<(), Box<dyn std::error::Error + Sync + Send + 'static>> {
+    if scripts.is_empty() {
+        return Ok(());
+    }
+
+    wait_for_http_ready(image).await?;
+
+    let user = image.user().expect("default user");
+    let pass = image.password().expect("default password");
+    let http_uri = image.http_uri_ipv4();
+
+    let client = reqwest::Client::new();
+
+    for script in scripts {
+        let statement = resolve_cypher_script(script)?;
+
+        let response: serde_json::Value = client
+            .post(format!("{http_uri}/db/{database}/tx/commit"))
+            .basic_auth(user, Some(pass))
+            .json(&serde_json::json!({ "statements": [{ "statement": statement }] }))
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        let errors = response.get("errors").and_then(serde_json::Value::as_array);
+        if let Some(errors) = errors {
+            if !errors.is_empty() {
+                return Err(
+                    format!("Neo4j reported errors while running init Cypher: {errors:?}").into(),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolve a [`CypherScript`] to the statement text that should be sent to Neo4j, reading it
+/// from disk for the [`CypherScript::File`] variant.
+fn resolve_cypher_script(
+    script: &CypherScript,
+) -> Result<String, Box<dyn std::error::Error + Sync + Send + 'static>> {
+    match script {
+        CypherScript::Statement(statement) => Ok(statement.clone()),
+        CypherScript::File(path) => std::fs::read_to_string(path).map_err(|err| {
+            format!(
+                "failed to read init Cypher script {}: {err}",
+                path.display()
+            )
+            .into()
+        }),
+    }
+}
+
+/// The four magic bytes that start every Bolt handshake request.
+const BOLT_MAGIC: [u8; 4] = [0x60, 0x60, 0xB0, 0x17];
+
+/// The four Bolt protocol versions we propose during the handshake, newest first. The server
+/// answers with whichever one (if any) it supports.
+const BOLT_PROPOSED_VERSIONS: [[u8; 4]; 4] = [
+    [0x00, 0x00, 0x04, 0x05],
+    [0x00, 0x00, 0x00, 0x05],
+    [0x00, 0x00, 0x04, 0x04],
+    [0x00, 0x00, 0x00, 0x03],
+];
+
+async fn wait_for_bolt_handshake(
+    image: &Neo4jImage,
+    timeout: Duration,
+    interval: Duration,
+) -> Result<(), Box<dyn std::error::Error + Sync + Send + 'static>> {
+    let host_port = image.bolt_uri_ipv4();
+    let host_port = host_port
+        .strip_prefix("bolt://")
+        .unwrap_or(host_port.as_str());
+
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut backoff = interval;
+
+    loop {
+        match try_bolt_handshake(host_port).await {
+            Ok(()) => return Ok(()),
+            Err(err) => {
+                let now = tokio::time::Instant::now();
+                if now >= deadline {
+                    return Err(format!(
+                        "Neo4j did not answer a Bolt handshake within {:?}: {}",
+                        timeout, err
+                    )
+                    .into());
+                }
+                tokio::time::sleep(backoff.min(deadline - now)).await;
+                backoff = (backoff * 2).min(Duration::from_secs(5));
+            }
+        }
+    }
+}
+
+async fn try_bolt_handshake(host_port: &str) -> std::io::Result<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut stream = tokio::net::TcpStream::connect(host_port).await?;
+
+    let mut request = Vec::with_capacity(20);
+    request.extend_from_slice(&BOLT_MAGIC);
+    for version in BOLT_PROPOSED_VERSIONS {
+        request.extend_from_slice(&version);
+    }
+    stream.write_all(&request).await?;
+
+    let mut negotiated = [0_u8; 4];
+    stream.read_exact(&mut negotiated).await?;
+
+    if negotiated == [0, 0, 0, 0] {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "server rejected all proposed Bolt versions",
+        ));
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -248,4 +810,122 @@ mod tests {
         });
         assert_eq!(env_var.as_deref(), Some("yes"))
     }
+
+    #[test]
+    fn bolt_readiness_carries_the_configured_timeout_and_interval() {
+        let img = RunnableImage::from(Neo4j::default());
+        let img =
+            img.with_bolt_readiness_timeout(Duration::from_secs(5), Duration::from_millis(50));
+
+        let bolt_readiness = img.bolt_readiness.unwrap();
+        assert_eq!(bolt_readiness.timeout, Duration::from_secs(5));
+        assert_eq!(bolt_readiness.interval, Duration::from_millis(50));
+    }
+
+    #[test]
+    fn init_cypher_defaults_to_the_neo4j_database() {
+        let img = RunnableImage::from(Neo4j::default());
+        let img = img.with_init_cypher([CypherScript::statement("RETURN 1")]);
+
+        assert_eq!(img.init_cypher.len(), 1);
+        assert_eq!(img.init_cypher_database, "neo4j");
+    }
+
+    #[test]
+    fn init_cypher_database_can_be_overridden() {
+        let img = RunnableImage::from(Neo4j::default());
+        let img = img
+            .with_init_cypher([CypherScript::statement("RETURN 1")])
+            .with_init_cypher_database("system");
+
+        assert_eq!(img.init_cypher_database, "system");
+    }
+
+    #[test]
+    fn resolve_cypher_script_reads_the_file_for_the_file_variant() {
+        let path =
+            std::env::temp_dir().join("neo4j_testcontainers_resolve_cypher_script_test.cypher");
+        std::fs::write(&path, "RETURN 1").unwrap();
+
+        let statement = resolve_cypher_script(&CypherScript::file(&path)).unwrap();
+
+        std::fs::remove_file(&path).ok();
+        assert_eq!(statement, "RETURN 1");
+    }
+
+    #[test]
+    fn resolve_cypher_script_reports_a_helpful_error_for_a_missing_file() {
+        let path = std::path::PathBuf::from("/no/such/init.cypher");
+
+        let err = resolve_cypher_script(&CypherScript::File(path.clone())).unwrap_err();
+
+        assert!(err.to_string().contains(&path.display().to_string()));
+    }
+
+    #[test]
+    fn plugins_set_the_neo4j_plugins_env_var_and_allowlist_their_procedures() {
+        let img = RunnableImage::from(Neo4j::default());
+        let img = img
+            .with_plugins(&[Plugin::Apoc, Plugin::GraphDataScience])
+            .unwrap();
+
+        let env_var = |key: &str| {
+            img.env_vars()
+                .find_map(|(k, v)| (k == key).then(|| v.clone()))
+        };
+
+        assert_eq!(
+            env_var("NEO4J_PLUGINS").as_deref(),
+            Some(r#"["apoc","graph-data-science"]"#)
+        );
+        assert_eq!(
+            env_var("NEO4J_dbms_security_procedures_unrestricted").as_deref(),
+            Some("apoc.*,gds.*")
+        );
+        assert_eq!(
+            env_var("NEO4J_dbms_security_procedures_allowlist").as_deref(),
+            Some("apoc.*,gds.*")
+        );
+    }
+
+    #[test]
+    fn enterprise_only_plugins_require_the_enterprise_edition() {
+        let img = RunnableImage::from(Neo4j::default());
+        let img = img.with_plugins(&[Plugin::Bloom]).unwrap();
+
+        assert_eq!(img.descriptor(), "neo4j:5-enterprise");
+    }
+
+    #[test]
+    fn in_network_uris_use_the_alias_and_unmapped_ports() {
+        let img = RunnableImage::from(Neo4j::default());
+        let img = img.image();
+
+        assert_eq!(img.bolt_uri_in_network("neo4j-db"), "bolt://neo4j-db:7687");
+        assert_eq!(img.http_uri_in_network("neo4j-db"), "http://neo4j-db:7474");
+    }
+
+    #[test]
+    fn memory_config_escapes_the_dotted_setting_names() {
+        let img = RunnableImage::from(Neo4j::default());
+        let img = img.with_memory_config("512m", "1G", "2G");
+
+        let env_var = |key: &str| {
+            img.env_vars()
+                .find_map(|(k, v)| (k == key).then(|| v.clone()))
+        };
+
+        assert_eq!(
+            env_var("NEO4J_server_memory_heap_initial__size").as_deref(),
+            Some("512m")
+        );
+        assert_eq!(
+            env_var("NEO4J_server_memory_heap_max__size").as_deref(),
+            Some("1G")
+        );
+        assert_eq!(
+            env_var("NEO4J_server_memory_pagecache_size").as_deref(),
+            Some("2G")
+        );
+    }
 }
@@ -0,0 +1,18 @@
+use neo4j_testcontainers::{prelude::*, Neo4j, RunnableImage};
+use neo4rs::Graph;
+
+#[tokio::test]
+async fn start_waits_for_a_real_bolt_handshake() {
+    let neo4j = RunnableImage::from(Neo4j::default()).with_bolt_readiness();
+    let container = neo4j.start().await.unwrap();
+
+    let uri = container.image().bolt_uri_ipv4();
+    let auth_user = container.image().user().expect("default user");
+    let auth_pass = container.image().password().expect("default password");
+
+    let graph = Graph::new(uri, auth_user, auth_pass).await.unwrap();
+    let mut result = graph.execute(neo4rs::query("RETURN 1")).await.unwrap();
+    let row = result.next().await.unwrap().unwrap();
+    let value: i64 = row.get("1").unwrap();
+    assert_eq!(1, value);
+}
@@ -0,0 +1,57 @@
+use neo4j_testcontainers::{prelude::*, CypherScript, Neo4j, RunnableImage};
+use neo4rs::Graph;
+
+#[tokio::test]
+async fn start_runs_init_cypher_scripts_before_returning() {
+    let neo4j = RunnableImage::from(Neo4j::default()).with_init_cypher([CypherScript::statement(
+        "CREATE (:Greeting {message: 'hello'})",
+    )]);
+    let container = neo4j.start().await.unwrap();
+
+    let uri = container.image().bolt_uri_ipv4();
+    let auth_user = container.image().user().expect("default user");
+    let auth_pass = container.image().password().expect("default password");
+
+    let graph = Graph::new(uri, auth_user, auth_pass).await.unwrap();
+    let mut result = graph
+        .execute(neo4rs::query(
+            "MATCH (g:Greeting) RETURN g.message AS message",
+        ))
+        .await
+        .unwrap();
+    let row = result.next().await.unwrap().unwrap();
+    let value: String = row.get("message").unwrap();
+    assert_eq!(value, "hello");
+}
+
+#[tokio::test]
+async fn start_runs_init_cypher_scripts_read_from_a_file() {
+    let script_path =
+        std::env::temp_dir().join("neo4j_testcontainers_init_cypher_file_test.cypher");
+    std::fs::write(
+        &script_path,
+        "CREATE (:Greeting {message: 'hello from file'})",
+    )
+    .unwrap();
+
+    let neo4j =
+        RunnableImage::from(Neo4j::default()).with_init_cypher([CypherScript::file(&script_path)]);
+    let container = neo4j.start().await.unwrap();
+
+    std::fs::remove_file(&script_path).ok();
+
+    let uri = container.image().bolt_uri_ipv4();
+    let auth_user = container.image().user().expect("default user");
+    let auth_pass = container.image().password().expect("default password");
+
+    let graph = Graph::new(uri, auth_user, auth_pass).await.unwrap();
+    let mut result = graph
+        .execute(neo4rs::query(
+            "MATCH (g:Greeting) RETURN g.message AS message",
+        ))
+        .await
+        .unwrap();
+    let row = result.next().await.unwrap().unwrap();
+    let value: String = row.get("message").unwrap();
+    assert_eq!(value, "hello from file");
+}
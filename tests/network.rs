@@ -0,0 +1,33 @@
+use bollard::Docker;
+use neo4j_testcontainers::{prelude::*, Neo4j, RunnableImage};
+use testcontainers_modules::testcontainers::runners::AsyncRunner as _;
+
+#[tokio::test]
+async fn network_alias_is_attached_to_the_custom_network() {
+    let network = "neo4j-test-network";
+    let alias = "neo4j-db";
+
+    let neo4j = RunnableImage::from(Neo4j::default())
+        .with_network(network)
+        .with_network_alias(alias);
+    let container = neo4j.start().await;
+
+    // Don't trust `bolt_uri_in_network`/`http_uri_in_network`'s own string formatting to prove
+    // the wiring; inspect the container's actual Docker network settings instead.
+    let docker = Docker::connect_with_local_defaults().unwrap();
+    let details = docker
+        .inspect_container(container.id(), None)
+        .await
+        .unwrap();
+
+    let networks = details
+        .network_settings
+        .and_then(|settings| settings.networks)
+        .expect("container has network settings");
+    let endpoint = networks
+        .get(network)
+        .expect("container is attached to the custom network");
+    let aliases = endpoint.aliases.clone().unwrap_or_default();
+
+    assert!(aliases.contains(&alias.to_owned()));
+}
@@ -0,0 +1,24 @@
+use neo4j_testcontainers::{prelude::*, Neo4j, Plugin, RunnableImage};
+use testcontainers_modules::testcontainers::runners::AsyncRunner as _;
+use neo4rs::Graph;
+
+#[tokio::test]
+async fn apoc_procedures_are_installed_and_callable() {
+    let neo4j = RunnableImage::from(Neo4j::default())
+        .with_plugins(&[Plugin::Apoc])
+        .unwrap();
+    let container = neo4j.start().await;
+
+    let uri = container.image().bolt_uri_ipv4();
+    let auth_user = container.image().user().expect("default user");
+    let auth_pass = container.image().password().expect("default password");
+
+    let graph = Graph::new(uri, auth_user, auth_pass).await.unwrap();
+    let mut result = graph
+        .execute(neo4rs::query("RETURN apoc.version() AS version"))
+        .await
+        .unwrap();
+    let row = result.next().await.unwrap().unwrap();
+    let version: String = row.get("version").unwrap();
+    assert!(!version.is_empty());
+}
@@ -0,0 +1,12 @@
+use neo4j_testcontainers::{prelude::*, Neo4j, RunnableImage};
+use testcontainers_modules::testcontainers::runners::AsyncRunner as _;
+
+#[tokio::test]
+async fn discover_version_reports_the_community_edition() {
+    let neo4j = RunnableImage::from(Neo4j::default());
+    let container = neo4j.start().await;
+
+    let version = container.image().discover_version().await.unwrap();
+
+    assert_eq!(version.edition, "community");
+}